@@ -0,0 +1,103 @@
+use async_openai::config::OpenAIConfig;
+use serde::Deserialize;
+
+/// A named LLM backend: which model to call, how to reach it, and the
+/// system prompt to seed conversations with. Lets the bot be pointed at
+/// Azure OpenAI, a local OpenAI-compatible server, or a different model
+/// without recompiling.
+#[derive(Debug, Clone, Deserialize)]
+pub struct BackendConfig {
+    pub name: String,
+    pub model: String,
+    pub max_tokens: u16,
+    pub system_prompt: String,
+    pub api_base: Option<String>,
+    pub api_key: Option<String>,
+}
+
+impl BackendConfig {
+    /// Builds a client scoped to this backend's API base and key, falling
+    /// back to `async-openai`'s own environment defaults when unset.
+    pub fn client(&self) -> async_openai::Client<OpenAIConfig> {
+        let mut config = OpenAIConfig::new();
+
+        if let Some(api_base) = &self.api_base {
+            config = config.with_api_base(api_base);
+        }
+
+        if let Some(api_key) = &self.api_key {
+            config = config.with_api_key(api_key);
+        }
+
+        async_openai::Client::with_config(config)
+    }
+}
+
+/// The set of backends the bot can route requests to, selected per-chat
+/// with `/model <name>` and resolved back to a `BackendConfig` on every
+/// `chat`/`group` request.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Config {
+    pub default_backend: String,
+    pub backends: Vec<BackendConfig>,
+}
+
+impl Config {
+    /// Loads the backend list from a TOML file, falling back to a single
+    /// `gpt-4` backend pointed at OpenAI when the file is absent. Fails if
+    /// `default_backend` doesn't name one of the loaded `backends`, so a
+    /// typo in the config is caught at startup rather than panicking on the
+    /// first message that needs to resolve it.
+    pub fn from_file(path: &str) -> anyhow::Result<Self> {
+        let config = match std::fs::read_to_string(path) {
+            Ok(raw) => toml::from_str(&raw)?,
+            Err(_) => Self::default(),
+        };
+
+        if config.backend(&config.default_backend).is_none() {
+            anyhow::bail!(
+                "default_backend `{}` does not match any configured backend",
+                config.default_backend
+            );
+        }
+
+        Ok(config)
+    }
+
+    pub fn backend(&self, name: &str) -> Option<&BackendConfig> {
+        self.backends.iter().find(|backend| backend.name == name)
+    }
+
+    /// Resolves a chat's chosen backend name to its config, falling back to
+    /// `default_backend` when unset or unknown.
+    pub fn resolve(&self, name: Option<&str>) -> &BackendConfig {
+        name.and_then(|name| self.backend(name))
+            .unwrap_or_else(|| self.default_backend())
+    }
+
+    /// Panics if `default_backend` doesn't resolve; `from_file` validates
+    /// this at load time so that can only happen if `Config` is built some
+    /// other way with an inconsistent value.
+    pub fn default_backend(&self) -> &BackendConfig {
+        self.backend(&self.default_backend)
+            .expect("default_backend must reference a configured backend")
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        let gpt4 = BackendConfig {
+            name: "gpt-4".to_string(),
+            model: "gpt-4".to_string(),
+            max_tokens: 512,
+            system_prompt: "You are GTP-4 a Telegram chat bot".to_string(),
+            api_base: None,
+            api_key: None,
+        };
+
+        Self {
+            default_backend: gpt4.name.clone(),
+            backends: vec![gpt4],
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use fluent::FluentArgs;
+use teloxide::{prelude::*, types::ParseMode};
+use tracing::error;
+use unic_langid::LanguageIdentifier;
+use uuid::Uuid;
+
+use crate::i18n::Catalog;
+
+/// Initializes the Sentry client from `SENTRY_DSN`, if set, so handler
+/// errors reported via [`report`] become searchable by their `error_id` tag.
+/// The returned guard must be kept alive for the process's lifetime (e.g.
+/// bound to a variable in `main`) so buffered events get flushed on drop.
+pub fn init() -> Option<sentry::ClientInitGuard> {
+    let dsn = std::env::var("SENTRY_DSN").ok()?;
+
+    Some(sentry::init((
+        dsn,
+        sentry::ClientOptions {
+            release: sentry::release_name!(),
+            ..Default::default()
+        },
+    )))
+}
+
+/// Generates a correlation ID for a handler failure, logs it, captures it in
+/// Sentry tagged with that ID (with the originating command attached as
+/// extra data), and tells the
+/// user which ID to mention when reporting the issue. Every handler should
+/// report through here so the ID shown to the user is always the one that
+/// was actually logged and sent to Sentry.
+pub async fn report(
+    bot: &Bot,
+    chat_id: ChatId,
+    catalog: &Catalog,
+    lang: &LanguageIdentifier,
+    command: &str,
+    error: impl std::fmt::Debug,
+) -> Result<(), anyhow::Error> {
+    let error_id = Uuid::new_v4().simple().to_string();
+
+    error!(error_id, command, ?error);
+
+    sentry::with_scope(
+        |scope| {
+            scope.set_tag("error_id", &error_id);
+            scope.set_extra("command", command.into());
+        },
+        || sentry::capture_message(&format!("{error:?}"), sentry::Level::Error),
+    );
+
+    let mut args = FluentArgs::new();
+    args.set("error_id", error_id);
+
+    bot.send_message(chat_id, catalog.tr(lang, "error-tracking", Some(&args)))
+        .parse_mode(ParseMode::MarkdownV2)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use fluent::{FluentArgs, FluentBundle, FluentResource};
+use std::collections::HashMap;
+use unic_langid::{langid, LanguageIdentifier};
+
+const EN_US: &str = include_str!("../locales/en-US.ftl");
+const ES_ES: &str = include_str!("../locales/es-ES.ftl");
+
+const FALLBACK: LanguageIdentifier = langid!("en-US");
+
+/// All loaded Fluent bundles, keyed by language tag, with `en-US` as the
+/// fallback used whenever a chat's resolved language (or a message key
+/// within it) isn't available.
+pub struct Catalog {
+    bundles: HashMap<LanguageIdentifier, FluentBundle<FluentResource>>,
+}
+
+impl Catalog {
+    pub fn load() -> Self {
+        let mut bundles = HashMap::new();
+        bundles.insert(FALLBACK, bundle(EN_US));
+        bundles.insert(langid!("es-ES"), bundle(ES_ES));
+        Self { bundles }
+    }
+
+    /// Resolves a Telegram `language_code` (e.g. `"es"`, `"pt-BR"`) to one of
+    /// our loaded bundles by primary language subtag, falling back to
+    /// `en-US` when unset or unsupported.
+    pub fn resolve(&self, language_code: Option<&str>) -> LanguageIdentifier {
+        language_code
+            .and_then(|code| code.parse::<LanguageIdentifier>().ok())
+            .and_then(|requested| {
+                self.bundles
+                    .keys()
+                    .find(|available| available.language == requested.language)
+                    .cloned()
+            })
+            .unwrap_or(FALLBACK)
+    }
+
+    /// Looks up `key` in `lang`'s bundle and formats it with `args`, falling
+    /// back to `en-US` when `lang`'s bundle isn't loaded *or* when it's
+    /// loaded but doesn't have `key` (e.g. a translation that hasn't caught
+    /// up with a newly added string yet). Returns the raw key when it's
+    /// missing from `en-US` too.
+    pub fn tr(&self, lang: &LanguageIdentifier, key: &str, args: Option<&FluentArgs>) -> String {
+        let message_in = |bundle: &FluentBundle<FluentResource>| {
+            bundle.get_message(key).and_then(|m| m.value())
+        };
+
+        let found = self
+            .bundles
+            .get(lang)
+            .and_then(|bundle| message_in(bundle).map(|value| (bundle, value)))
+            .or_else(|| {
+                self.bundles
+                    .get(&FALLBACK)
+                    .and_then(|bundle| message_in(bundle).map(|value| (bundle, value)))
+            });
+
+        let Some((bundle, message)) = found else {
+            return key.to_string();
+        };
+
+        let mut errors = vec![];
+        bundle
+            .format_pattern(message, args, &mut errors)
+            .to_string()
+    }
+}
+
+fn bundle(source: &str) -> FluentBundle<FluentResource> {
+    let resource =
+        FluentResource::try_new(source.to_string()).expect("locale file is not valid Fluent");
+
+    let mut bundle = FluentBundle::new(vec![FALLBACK]);
+    bundle
+        .add_resource(resource)
+        .expect("locale file has a duplicate message key");
+
+    bundle
+}
@@ -2,21 +2,23 @@ use async_openai::{
     error::OpenAIError,
     types::{
         ChatCompletionRequestMessage, ChatCompletionRequestMessageArgs,
-        CreateChatCompletionRequestArgs, CreateChatCompletionResponse,
+        ChatCompletionResponseStream, CreateChatCompletionRequestArgs,
     },
-    Client,
 };
-use teloxide::types::Message;
 use tracing::instrument;
 
-use crate::telegram_bot;
+use crate::{
+    config::BackendConfig,
+    telegram_bot::{self, GroupMessage},
+};
 
-#[instrument]
+#[instrument(skip(backend))]
 pub async fn group_question(
-    messages: &[Message],
+    messages: &[GroupMessage],
     question: String,
-) -> Result<CreateChatCompletionResponse, OpenAIError> {
-    let client = Client::new();
+    backend: &BackendConfig,
+) -> Result<ChatCompletionResponseStream, OpenAIError> {
+    let client = backend.client();
 
     let system_message = ChatCompletionRequestMessage {
         role: async_openai::types::Role::System,
@@ -28,11 +30,9 @@ pub async fn group_question(
     let mut chat_history = String::new();
 
     for message in messages {
-        let username = message.from().and_then(|user| user.username.clone());
-        let message_text = message.text();
         let message_time = message.date.naive_local();
 
-        if let (Some(username), Some(message_text)) = (username, message_text) {
+        if let (Some(username), Some(message_text)) = (&message.username, &message.text) {
             chat_history
                 .push_str(format!("{} [{}]: {}\n", username, message_time, message_text).as_str())
         }
@@ -48,12 +48,12 @@ pub async fn group_question(
     };
 
     let request = CreateChatCompletionRequestArgs::default()
-        .max_tokens(512u16)
-        .model("gpt-4")
+        .max_tokens(backend.max_tokens)
+        .model(&backend.model)
         .messages(vec![system_message, task_message])
         .build()?;
 
-    client.chat().create(request).await
+    client.chat().create_stream(request).await
 }
 
 impl From<telegram_bot::Role> for async_openai::types::Role {
@@ -80,19 +80,16 @@ impl From<telegram_bot::BotMessage> for async_openai::types::ChatCompletionReque
     }
 }
 
-#[instrument]
+#[instrument(skip(backend))]
 pub async fn reply(
     messages: &[ChatCompletionRequestMessage],
-    system: Option<&str>,
-    model: Option<&str>,
-) -> Result<CreateChatCompletionResponse, OpenAIError> {
-    let client = Client::new();
+    backend: &BackendConfig,
+) -> Result<ChatCompletionResponseStream, OpenAIError> {
+    let client = backend.client();
 
     let system_msg = ChatCompletionRequestMessage {
         role: async_openai::types::Role::System,
-        content: system
-            .unwrap_or("You are GTP-4 a Telegram chat bot")
-            .to_string(),
+        content: backend.system_prompt.clone(),
         name: None,
     };
 
@@ -101,10 +98,10 @@ pub async fn reply(
     request_messages.extend_from_slice(messages);
 
     let request = CreateChatCompletionRequestArgs::default()
-        .max_tokens(512u16)
-        .model(model.unwrap_or("gpt-4"))
-        .messages(messages)
+        .max_tokens(backend.max_tokens)
+        .model(&backend.model)
+        .messages(request_messages)
         .build()?;
 
-    client.chat().create(request).await
+    client.chat().create_stream(request).await
 }
\ No newline at end of file
@@ -1,66 +1,202 @@
 use crate::{
+    config::Config,
+    error_reporting,
+    i18n::Catalog,
     openai_client::{self, reply},
     replicate_client::ReplicateClient,
 };
+use async_openai::types::ChatCompletionResponseStream;
+use chrono::{DateTime, Utc};
 use dptree::case;
+use fluent::FluentArgs;
+use futures::StreamExt;
 use reqwest::Url;
-use serde::Serialize;
-use std::fmt::Display;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
 use teloxide::{
     dispatching::{
-        dialogue::{self, InMemStorage},
+        dialogue::{self, serializer::Json, ErasedStorage, InMemStorage, RedisStorage, SqliteStorage, Storage},
         UpdateHandler,
     },
     filter_command,
     prelude::*,
-    types::{InputFile, InputMedia, InputMediaPhoto, ParseMode},
+    types::{
+        BotCommand, CallbackQuery, InlineKeyboardButton, InlineKeyboardMarkup, InputFile,
+        InputMedia, InputMediaPhoto, ParseMode,
+    },
     utils::{command::BotCommands, markdown::escape},
 };
-use tracing::{error, instrument};
+use tracing::instrument;
 use uuid::Uuid;
 
+// `description` holds the Fluent key for this command, not the literal
+// text: `BotCommands` needs a compile-time string, so the localized text is
+// looked up from it at startup via `localized_commands`.
 #[derive(BotCommands, Clone, Debug)]
-#[command(
-    rename_rule = "lowercase",
-    description = "These commands are supported:"
-)]
+#[command(rename_rule = "lowercase", description = "commands-header")]
 pub enum Command {
-    #[command(description = "Keep the conversation going, the bot will keep context until /reset")]
+    #[command(description = "chat-description")]
     Chat { text: String },
-    #[command(description = "Create an image using Stable Diffusion v1.5")]
+    #[command(description = "image-description")]
     Image { text: String },
-    #[command(description = "Ask questions in the context of the group conversation")]
+    #[command(description = "group-description")]
     Group { text: String },
-    #[command(description = "Wipe chat from the bot's memory")]
+    #[command(description = "reset-description")]
     Reset,
+    #[command(description = "model-description")]
+    Model { name: String },
+}
+
+/// Resolves `Command::bot_commands()`'s Fluent-keyed descriptions into
+/// `lang`'s localized text, ready to hand to `bot.set_my_commands`.
+pub fn localized_commands(catalog: &Catalog, lang: &unic_langid::LanguageIdentifier) -> Vec<BotCommand> {
+    Command::bot_commands()
+        .into_iter()
+        .map(|mut command| {
+            command.description = catalog.tr(lang, &command.description, None);
+            command
+        })
+        .collect()
+}
+
+/// Resolves the localized header shown above the command list in `/help`.
+pub fn commands_header(catalog: &Catalog, lang: &unic_langid::LanguageIdentifier) -> String {
+    catalog.tr(lang, "commands-header", None)
+}
+
+/// Resolves a chat member's preferred language from their Telegram
+/// `language_code`, falling back to `en-US` when unset or unsupported.
+fn user_lang(catalog: &Catalog, message: &Message) -> unic_langid::LanguageIdentifier {
+    catalog.resolve(message.from().and_then(|user| user.language_code.as_deref()))
+}
+
+/// An action a user can pick from the inline keyboard attached to a
+/// generated image, encoded as a single trailing byte in `callback_data`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageAction {
+    Regenerate,
+    Upscale,
+    Keep,
 }
 
-#[derive(Debug, Clone, Copy, Serialize)]
+impl ImageAction {
+    fn as_char(self) -> char {
+        match self {
+            ImageAction::Regenerate => 'r',
+            ImageAction::Upscale => 'u',
+            ImageAction::Keep => 'k',
+        }
+    }
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            'r' => Some(ImageAction::Regenerate),
+            'u' => Some(ImageAction::Upscale),
+            'k' => Some(ImageAction::Keep),
+            _ => None,
+        }
+    }
+}
+
+/// The prompt an in-flight image request was generated from, plus the
+/// output URLs it produced, kept around so a button press on its inline
+/// keyboard can regenerate (from the prompt) or upscale (from the outputs)
+/// without the user retyping anything.
+#[derive(Debug, Clone)]
+pub struct PendingImage {
+    prompt: String,
+    outputs: Vec<String>,
+}
+
+/// Pending image requests, keyed by the `Uuid` embedded in their inline
+/// keyboard's `callback_data`.
+pub type PendingImages = Arc<Mutex<HashMap<Uuid, PendingImage>>>;
+
+fn image_keyboard(request_id: Uuid) -> InlineKeyboardMarkup {
+    let id = request_id.simple().to_string();
+
+    let button = |label: &str, action: ImageAction| {
+        InlineKeyboardButton::callback(label, format!("{id}{}", action.as_char()))
+    };
+
+    InlineKeyboardMarkup::new([[
+        button("🔁 Regenerate", ImageAction::Regenerate),
+        button("⬆️ Upscale", ImageAction::Upscale),
+        button("✅ Keep", ImageAction::Keep),
+    ]])
+}
+
+fn parse_callback_data(data: &str) -> Option<(Uuid, ImageAction)> {
+    if data.len() != 33 {
+        return None;
+    }
+
+    let (id, action) = data.split_at(32);
+    let request_id = Uuid::try_parse(id).ok()?;
+    let action = ImageAction::from_char(action.chars().next()?)?;
+
+    Some((request_id, action))
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub enum Role {
     System,
     User,
     Assistant,
 }
 
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct BotMessage {
     pub role: Role,
     pub content: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
 }
 
+/// A trimmed-down, serializable stand-in for `teloxide::types::Message`,
+/// keeping only the fields `group_question` reads.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct GroupMessage {
+    pub username: Option<String>,
+    pub text: Option<String>,
+    pub date: DateTime<Utc>,
+}
+
+impl From<&Message> for GroupMessage {
+    fn from(message: &Message) -> Self {
+        Self {
+            username: message.from().and_then(|user| user.username.clone()),
+            text: message.text().map(str::to_owned),
+            date: message.date,
+        }
+    }
+}
+
 type BotHistory = Vec<BotMessage>;
 
-type GroupHistory = Vec<Message>;
+type GroupHistory = Vec<GroupMessage>;
+
+/// Cap on `History.group_history`, oldest-first. With persistent dialogue
+/// storage (`DIALOGUE_STORAGE=sqlite`/`redis`) group history survives
+/// restarts, so unlike `bot_history` it has no user-facing `/reset`
+/// equivalent to keep it bounded — trim it here instead.
+const MAX_GROUP_HISTORY: usize = 50;
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct History {
     group_history: GroupHistory,
     bot_history: BotHistory,
+    /// Name of the selected backend in `Config`, set via `/model`; `None`
+    /// means "use `Config::default_backend`".
+    backend: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum State {
     Offline,
     Online(History),
@@ -81,6 +217,41 @@ impl Display for State {
     }
 }
 
+/// Which dialogue backend to persist `State` to, selected via the
+/// `DIALOGUE_STORAGE` environment variable ("sqlite" or "redis"); falls back
+/// to an in-memory store (lost on restart) when unset.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum StorageBackend {
+    #[default]
+    Memory,
+    Sqlite,
+    Redis,
+}
+
+impl StorageBackend {
+    pub fn from_env() -> Self {
+        match std::env::var("DIALOGUE_STORAGE").as_deref() {
+            Ok("sqlite") => Self::Sqlite,
+            Ok("redis") => Self::Redis,
+            _ => Self::Memory,
+        }
+    }
+}
+
+pub async fn storage(backend: StorageBackend) -> Arc<ErasedStorage<State>> {
+    match backend {
+        StorageBackend::Memory => InMemStorage::<State>::new().erase(),
+        StorageBackend::Sqlite => SqliteStorage::open("dialogues.sqlite", Json)
+            .await
+            .expect("failed to open sqlite dialogue storage")
+            .erase(),
+        StorageBackend::Redis => RedisStorage::open("redis://127.0.0.1:6379", Json)
+            .await
+            .expect("failed to connect to redis dialogue storage")
+            .erase(),
+    }
+}
+
 #[instrument]
 pub fn schema() -> UpdateHandler<anyhow::Error> {
     let cmd_handler = filter_command::<Command, _>().branch(
@@ -88,7 +259,8 @@ pub fn schema() -> UpdateHandler<anyhow::Error> {
             .branch(case![Command::Group { text }].endpoint(group))
             .branch(case![Command::Reset].endpoint(reset))
             .branch(case![Command::Chat { text }].endpoint(chat))
-            .branch(case![Command::Image { text }].endpoint(image)),
+            .branch(case![Command::Image { text }].endpoint(image))
+            .branch(case![Command::Model { name }].endpoint(model)),
     );
 
     let msg_handler = Update::filter_message()
@@ -97,16 +269,97 @@ pub fn schema() -> UpdateHandler<anyhow::Error> {
         .branch(case![State::Online(msgs)].endpoint(record))
         .endpoint(do_nothing);
 
-    dialogue::enter::<Update, InMemStorage<State>, State, _>().branch(msg_handler)
+    let callback_handler = Update::filter_callback_query().endpoint(image_callback);
+
+    dialogue::enter::<Update, ErasedStorage<State>, State, _>()
+        .branch(msg_handler)
+        .branch(callback_handler)
 }
 
-type InMemDialogue = Dialogue<State, InMemStorage<State>>;
+type InMemDialogue = Dialogue<State, ErasedStorage<State>>;
 
 type HandlerResult = Result<(), anyhow::Error>;
 
+/// How often the streamed placeholder message is re-edited with newly
+/// arrived deltas, whichever of the two comes first.
+const EDIT_INTERVAL: Duration = Duration::from_millis(750);
+const EDIT_TOKEN_INTERVAL: usize = 40;
+
+/// Streamed completions don't carry a final `usage`, so the "reaching 8k
+/// limit" warning is driven by this rough ~4-characters-per-token estimate
+/// instead.
+fn estimate_tokens(text: &str) -> usize {
+    (text.len() / 4).max(1)
+}
+
+/// Sends a placeholder message, then accumulates `stream`'s deltas into it,
+/// throttling edits to `EDIT_INTERVAL`/`EDIT_TOKEN_INTERVAL` so Telegram
+/// doesn't rate-limit us. Returns the fully accumulated, unescaped text
+/// together with the placeholder message's id, so the caller can edit it
+/// again.
+///
+/// A mid-stream error is reported through `error_reporting::report` here
+/// (under `command`) rather than left for the caller, since by this point
+/// the caller's own `create_stream` call has already succeeded and has
+/// nothing left to match on.
+async fn stream_into_message(
+    bot: &Bot,
+    catalog: &Catalog,
+    lang: &unic_langid::LanguageIdentifier,
+    command: &str,
+    chat_id: ChatId,
+    mut stream: ChatCompletionResponseStream,
+) -> Result<(String, MessageId), anyhow::Error> {
+    let placeholder = bot.send_message(chat_id, "…").await?;
+
+    let mut text = String::new();
+    let mut last_sent = String::new();
+    let mut last_edit = Instant::now();
+    let mut tokens_since_edit = 0usize;
+
+    while let Some(chunk) = stream.next().await {
+        let chunk = match chunk {
+            Ok(chunk) => chunk,
+            Err(e) => {
+                error_reporting::report(bot, chat_id, catalog, lang, command, e).await?;
+                anyhow::bail!("stream interrupted mid-response");
+            }
+        };
+
+        for choice in chunk.choices {
+            if let Some(delta) = choice.delta.content {
+                tokens_since_edit += estimate_tokens(&delta);
+                text.push_str(&delta);
+            }
+        }
+
+        if !text.is_empty()
+            && text != last_sent
+            && (last_edit.elapsed() >= EDIT_INTERVAL || tokens_since_edit >= EDIT_TOKEN_INTERVAL)
+        {
+            bot.edit_message_text(chat_id, placeholder.id, escape(&text))
+                .parse_mode(ParseMode::MarkdownV2)
+                .await?;
+
+            last_sent = text.clone();
+            last_edit = Instant::now();
+            tokens_since_edit = 0;
+        }
+    }
+
+    if text != last_sent {
+        bot.edit_message_text(chat_id, placeholder.id, escape(&text))
+            .parse_mode(ParseMode::MarkdownV2)
+            .await?;
+    }
+
+    Ok((text, placeholder.id))
+}
+
 async fn group(
     bot: Bot,
-    client: async_openai::Client,
+    config: Arc<Config>,
+    catalog: Arc<Catalog>,
     text: String,
     message: Message,
     history: History,
@@ -114,75 +367,163 @@ async fn group(
     bot.send_chat_action(message.chat.id, teloxide::types::ChatAction::Typing)
         .await?;
 
+    let backend = config.resolve(history.backend.as_deref());
+
     let openai_response =
-        openai_client::group_question(&history.group_history, text, Some(client)).await;
+        openai_client::group_question(&history.group_history, text, backend).await;
+
+    let lang = user_lang(&catalog, &message);
 
     match openai_response {
         Err(e) => {
-            let error_id = Uuid::new_v4().simple().to_string();
-
-            error!(error_id, ?e);
-
-            bot.send_message(
-                message.chat.id,
-                format!("there was an error processing your request, you can use this ID to track the issue `{}`", error_id),
-            ).parse_mode(ParseMode::MarkdownV2)
-            .await?;
+            error_reporting::report(&bot, message.chat.id, &catalog, &lang, "group", e).await?;
         }
-        Ok(responses) => {
-            let reply_text = responses
-                .choices
-                .into_iter()
-                .map(|choice| choice.message.content)
-                .collect::<String>();
-
-            bot.send_message(message.chat.id, escape(&reply_text))
-                .parse_mode(ParseMode::MarkdownV2)
-                .await?;
+        Ok(stream) => {
+            stream_into_message(&bot, &catalog, &lang, "group", message.chat.id, stream).await?;
         }
     }
 
     Ok(())
 }
 
-async fn image(bot: Bot, client: ReplicateClient, text: String, message: Message) -> HandlerResult {
-    bot.send_chat_action(message.chat.id, teloxide::types::ChatAction::UploadPhoto)
-        .await?;
-
-    let replicate_response = client.image(text.clone()).await?;
-
-    match replicate_response.output {
+/// Shared tail of `image` and `image_callback`: on a successful Replicate
+/// response, posts the generated photos, stores a fresh `PendingImage`
+/// keyed by a new request id, and prompts what to do with it next; on
+/// failure, reports the error under `command`. Kept in one place since
+/// `image_callback`'s regenerate and upscale arms both land here too.
+async fn send_image_result(
+    bot: &Bot,
+    pending: &PendingImages,
+    catalog: &Catalog,
+    lang: &unic_langid::LanguageIdentifier,
+    chat_id: ChatId,
+    command: &str,
+    prompt: String,
+    output: Option<Option<Vec<String>>>,
+    error: impl std::fmt::Debug,
+) -> HandlerResult {
+    match output {
         Some(output) => {
             let outputs = output.unwrap_or(vec![]);
 
             let media = outputs.iter().filter_map(|photo_url| {
-                let Ok(url) = Url::parse(&photo_url) else {
+                let Ok(url) = Url::parse(photo_url) else {
                     return None
                 };
                 Some(InputMedia::Photo(InputMediaPhoto::new(InputFile::url(url))))
             });
 
-            bot.send_media_group(message.chat.id, media).await?;
-        }
-        None => {
-            let error_id = Uuid::new_v4().simple().to_string();
+            bot.send_media_group(chat_id, media).await?;
 
-            error!(error_id, ?replicate_response.error);
+            let request_id = Uuid::new_v4();
 
-            bot.send_message(
-                message.chat.id,
-                format!("there was an error processing your request, you can use this ID to track the issue `{}`", error_id),
-            ).parse_mode(ParseMode::MarkdownV2)
-            .await?;
+            pending
+                .lock()
+                .unwrap()
+                .insert(request_id, PendingImage { prompt, outputs });
+
+            bot.send_message(chat_id, catalog.tr(lang, "image-prompt", None))
+                .reply_markup(image_keyboard(request_id))
+                .await?;
+        }
+        None => {
+            error_reporting::report(bot, chat_id, catalog, lang, command, error).await?;
         }
     };
 
     Ok(())
 }
 
+async fn image(
+    bot: Bot,
+    client: ReplicateClient,
+    pending: PendingImages,
+    catalog: Arc<Catalog>,
+    text: String,
+    message: Message,
+) -> HandlerResult {
+    bot.send_chat_action(message.chat.id, teloxide::types::ChatAction::UploadPhoto)
+        .await?;
+
+    let replicate_response = client.image(text.clone()).await?;
+    let lang = user_lang(&catalog, &message);
+
+    send_image_result(
+        &bot,
+        &pending,
+        &catalog,
+        &lang,
+        message.chat.id,
+        "image",
+        text,
+        replicate_response.output,
+        replicate_response.error,
+    )
+    .await
+}
+
+async fn image_callback(
+    bot: Bot,
+    client: ReplicateClient,
+    pending: PendingImages,
+    catalog: Arc<Catalog>,
+    query: CallbackQuery,
+) -> HandlerResult {
+    bot.answer_callback_query(&query.id).await?;
+
+    let lang = catalog.resolve(query.from.language_code.as_deref());
+
+    let Some(data) = &query.data else {
+        return Ok(());
+    };
+
+    let Some((request_id, action)) = parse_callback_data(data) else {
+        return Ok(());
+    };
+
+    let Some(message) = &query.message else {
+        return Ok(());
+    };
+
+    // Every action (including a failed one) consumes the pressed button, so
+    // the entry is removed here rather than left for the map to accumulate.
+    let pending_image = pending.lock().unwrap().remove(&request_id);
+
+    if action == ImageAction::Keep {
+        return Ok(());
+    }
+
+    let Some(pending_image) = pending_image else {
+        return Ok(());
+    };
+
+    bot.send_chat_action(message.chat.id, teloxide::types::ChatAction::UploadPhoto)
+        .await?;
+
+    let replicate_response = match action {
+        ImageAction::Regenerate => client.image(pending_image.prompt.clone()).await?,
+        ImageAction::Upscale => client.upscale(pending_image.outputs.clone()).await?,
+        ImageAction::Keep => unreachable!(),
+    };
+
+    send_image_result(
+        &bot,
+        &pending,
+        &catalog,
+        &lang,
+        message.chat.id,
+        "image_callback",
+        pending_image.prompt,
+        replicate_response.output,
+        replicate_response.error,
+    )
+    .await
+}
+
 async fn reset(
     bot: Bot,
     dialogue: InMemDialogue,
+    catalog: Arc<Catalog>,
     message: Message,
     mut history: History,
 ) -> HandlerResult {
@@ -193,13 +534,57 @@ async fn reset(
 
     dialogue.update(State::Online(history)).await?;
 
-    bot.send_message(message.chat.id, "`Bot chat history has been erased` ✅")
+    let lang = user_lang(&catalog, &message);
+
+    bot.send_message(message.chat.id, catalog.tr(&lang, "reset-done", None))
         .parse_mode(ParseMode::MarkdownV2)
         .await?;
 
     Ok(())
 }
 
+async fn model(
+    bot: Bot,
+    dialogue: InMemDialogue,
+    config: Arc<Config>,
+    catalog: Arc<Catalog>,
+    name: String,
+    message: Message,
+    mut history: History,
+) -> HandlerResult {
+    let lang = user_lang(&catalog, &message);
+
+    if config.backend(&name).is_none() {
+        let known = config
+            .backends
+            .iter()
+            .map(|backend| backend.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let mut args = FluentArgs::new();
+        args.set("name", name);
+        args.set("backends", known);
+
+        bot.send_message(message.chat.id, catalog.tr(&lang, "unknown-backend", Some(&args)))
+            .await?;
+
+        return Ok(());
+    }
+
+    history.backend = Some(name.clone());
+
+    dialogue.update(State::Online(history)).await?;
+
+    let mut args = FluentArgs::new();
+    args.set("name", name);
+
+    bot.send_message(message.chat.id, catalog.tr(&lang, "backend-selected", Some(&args)))
+        .await?;
+
+    Ok(())
+}
+
 async fn do_nothing() -> HandlerResult {
     // if the bot is muted do nothing
     Ok(())
@@ -210,7 +595,11 @@ async fn record(
     new_message: Message,
     mut history: History,
 ) -> HandlerResult {
-    history.group_history.push(new_message);
+    history.group_history.push(GroupMessage::from(&new_message));
+
+    let overflow = history.group_history.len().saturating_sub(MAX_GROUP_HISTORY);
+    history.group_history.drain(..overflow);
+
     dialogue.update(State::Online(history)).await?;
     Ok(())
 }
@@ -218,11 +607,13 @@ async fn record(
 async fn chat(
     bot: Bot,
     dialogue: InMemDialogue,
-    client: async_openai::Client,
+    config: Arc<Config>,
+    catalog: Arc<Catalog>,
     text: String,
     message: Message,
     mut history: History,
 ) -> HandlerResult {
+    let lang = user_lang(&catalog, &message);
     let username = message.from().and_then(|user| user.username.clone());
 
     history.bot_history.push(BotMessage {
@@ -234,6 +625,8 @@ async fn chat(
     bot.send_chat_action(message.chat.id, teloxide::types::ChatAction::Typing)
         .await?;
 
+    let backend = config.resolve(history.backend.as_deref());
+
     let results = reply(
         &history
             .bot_history
@@ -241,57 +634,48 @@ async fn chat(
             .into_iter()
             .map(|m| m.into())
             .collect::<Vec<_>>(),
-        Some(client),
-        None,
-        None,
+        backend,
     )
     .await;
 
     match results {
         Err(e) => {
-            let error_id = Uuid::new_v4().simple().to_string();
-
-            error!(error_id, ?e);
-
-            bot.send_message(
-                message.chat.id,
-                format!("there was an error processing your request, you can use this ID to track the issue `{}`", error_id),
-            ).parse_mode(ParseMode::MarkdownV2)
-            .await?;
+            error_reporting::report(&bot, message.chat.id, &catalog, &lang, "chat", e).await?;
         }
-        Ok(results) => {
+        Ok(stream) => {
             let botname = &bot.get_me().await?.username;
 
-            let mut reply_txt = String::new();
-
-            for choice in results.choices {
-                let result = choice.message.content;
+            let (reply_text, message_id) =
+                stream_into_message(&bot, &catalog, &lang, "chat", message.chat.id, stream).await?;
 
-                reply_txt.push_str(&result);
+            history.bot_history.push(BotMessage {
+                role: Role::Assistant,
+                content: reply_text.clone(),
+                name: botname.clone(),
+            });
 
-                history.bot_history.push(BotMessage {
-                    role: Role::Assistant,
-                    content: result,
-                    name: botname.clone(),
-                });
-            }
+            let conversation_tokens = history
+                .bot_history
+                .iter()
+                .map(|message| estimate_tokens(&message.content))
+                .sum::<usize>();
 
             dialogue.update(State::Online(history)).await.unwrap();
 
-            reply_txt = escape(&reply_txt);
+            let mut tokens_args = FluentArgs::new();
+            tokens_args.set("tokens", conversation_tokens as i64);
 
-            if let Some(usage) = results.usage {
-                reply_txt.push_str(&format!(
-                    "\n\n`usage {} tokens = {} prompt + {} completion`",
-                    usage.total_tokens, usage.prompt_tokens, usage.completion_tokens
-                ));
+            let mut reply_txt = escape(&reply_text);
+            reply_txt.push('\n');
+            reply_txt.push('\n');
+            reply_txt.push_str(&catalog.tr(&lang, "usage-estimated", Some(&tokens_args)));
 
-                if usage.total_tokens > 6000 {
-                    reply_txt.push_str("\n`Reaching 8k limit, consider running /reset soon`")
-                }
+            if conversation_tokens > 6000 {
+                reply_txt.push('\n');
+                reply_txt.push_str(&catalog.tr(&lang, "reaching-limit", None));
             }
 
-            bot.send_message(message.chat.id, &reply_txt)
+            bot.edit_message_text(message.chat.id, message_id, &reply_txt)
                 .parse_mode(ParseMode::MarkdownV2)
                 .await?;
         }